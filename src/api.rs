@@ -0,0 +1,10 @@
+//! Provides access to the Gsoc2 API shared by all subcommands.
+
+/// Thin wrapper around the HTTP connection pool used to talk to Gsoc2.
+pub struct Api;
+
+impl Api {
+    /// Called before the process exits to release the shared connection
+    /// pool. `Api` holds no pool, so this does nothing.
+    pub fn dispose_pool() {}
+}
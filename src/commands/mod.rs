@@ -1,10 +1,11 @@
 //! This module implements the root command of the CLI tool.
 
 use std::env;
+use std::fs;
 use std::io;
 use std::process;
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
 use clap_complete::{generate, Generator, Shell};
 use log::{debug, info, set_logger, set_max_level, LevelFilter};
@@ -13,8 +14,10 @@ use crate::api::Api;
 use crate::config::{Auth, Config};
 use crate::constants::{ARCH, PLATFORM, VERSION};
 use crate::utils::auth_token::AuthToken;
+use crate::utils::blackbox;
 use crate::utils::logging::set_quiet_mode;
 use crate::utils::logging::Logger;
+use crate::utils::shell_completions::{completion_path, detect_shell, rc_snippet};
 use crate::utils::system::{init_backtrace, load_dotenv, print_error, QuietExit};
 use crate::utils::update::run_gsoc2cli_update_nagger;
 
@@ -121,6 +124,12 @@ fn configure_args(config: &mut Config, matches: &ArgMatches) -> Result<()> {
         config.set_headers(headers);
     }
 
+    if let Some(overrides) = matches.get_many::<String>("config") {
+        for spec in overrides {
+            config.set_value_from_override(spec)?;
+        }
+    }
+
     Ok(())
 }
 
@@ -147,6 +156,18 @@ fn app() -> Command {
             "Fully qualified URL to the Gsoc2 server.{n}\
              [default: https://gsoc2.io/]",
         ))
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .value_name("SECTION.KEY=VALUE")
+                .action(ArgAction::Append)
+                .global(true)
+                .help(
+                    "Override a single config value for this invocation, in{n}\
+                     `section.key=value` form. Can be repeated. Takes{n}\
+                     precedence over the value in the config file.",
+                ),
+        )
         .arg(
             Arg::new("headers")
                 .long("header")
@@ -202,9 +223,25 @@ fn app() -> Command {
             .arg_required_else_help(true)
             .arg(
                 Arg::new("shell")
-                    .help("The shell to print completions for.")
+                    .help(
+                        "The shell to generate completions for.{n}\
+                         [default with --install or --print-path: detected from{n}\
+                         the parent process, then $SHELL]",
+                    )
                     .value_parser(value_parser!(Shell)),
             )
+            .arg(
+                Arg::new("install")
+                    .long("install")
+                    .action(ArgAction::SetTrue)
+                    .help("Write the completions to the shell's standard completion directory instead of stdout."),
+            )
+            .arg(
+                Arg::new("print_path")
+                    .long("print-path")
+                    .action(ArgAction::SetTrue)
+                    .help("Print the path --install would write to, without writing it."),
+            )
         )
 }
 
@@ -274,17 +311,53 @@ pub fn execute() -> Result<()> {
         VERSION, PLATFORM, ARCH
     );
 
-    info!(
-        "gsoc2-cli was invoked with the following command line: {}",
-        env::args()
-            .map(|a| format!("\"{a}\""))
-            .collect::<Vec<String>>()
-            .join(" ")
-    );
+    let argv = env::args()
+        .map(|a| format!("\"{a}\""))
+        .collect::<Vec<String>>()
+        .join(" ");
+    info!("gsoc2-cli was invoked with the following command line: {}", argv);
+    blackbox::begin(argv);
+    if let Some(subcommand) = matches.subcommand_name() {
+        blackbox::set_subcommand(subcommand);
+    }
 
     if let Some(argmatches) = matches.subcommand_matches("completions") {
         let mut cmd = app();
         cmd = add_commands(cmd);
+
+        if argmatches.get_flag("install") || argmatches.get_flag("print_path") {
+            let shell = argmatches
+                .get_one::<Shell>("shell")
+                .copied()
+                .or_else(detect_shell)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "could not detect your shell from $SHELL; pass it explicitly, e.g. `completions --install zsh`"
+                    )
+                })?;
+            let bin_name = cmd.get_name().to_string();
+            let path = completion_path(shell, &bin_name)?;
+
+            if argmatches.get_flag("print_path") {
+                println!("{}", path.display());
+                return Ok(());
+            }
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = fs::File::create(&path)?;
+            generate(shell, &mut cmd, bin_name, &mut file);
+
+            println!("Installed {shell} completions to {}", path.display());
+            if let Some(snippet) = rc_snippet(shell, &path) {
+                println!("Add this to your shell's rc file if it isn't picked up automatically:");
+                println!();
+                println!("    {snippet}");
+            }
+            return Ok(());
+        }
+
         if let Some(generator) = argmatches.get_one::<Shell>("shell") {
             eprintln!("Generating completion file for {generator}...");
             print_completions(*generator, &mut cmd);
@@ -339,6 +412,8 @@ pub fn main() {
         }
     };
 
+    blackbox::finish(exit_code);
+
     // before we shut down we unbind the api to give the connection pool
     // a chance to collect.  Not doing so has shown to cause hung threads
     // on windows.
@@ -1,12 +1,14 @@
 use std::env;
-use std::fs;
 
 use anyhow::Result;
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use console::style;
 
-use crate::utils::fs::is_writable;
-use crate::utils::system::{is_homebrew_install, is_npm_install, QuietExit};
+use crate::utils::fs::{
+    displace_file, displace_file_elevated, is_writable, remove_displaced_file,
+    remove_displaced_file_elevated,
+};
+use crate::utils::system::{homebrew_prefix, is_homebrew_install, is_npm_install, QuietExit};
 use crate::utils::ui::prompt_to_continue;
 
 pub fn make_command(command: Command) -> Command {
@@ -17,7 +19,7 @@ pub fn make_command(command: Command) -> Command {
             .help("Skip uninstall confirmation prompt."),
     );
 
-    if cfg!(windows) || is_homebrew_install() || is_npm_install() {
+    if is_homebrew_install() || is_npm_install() {
         command.hide(true)
     } else {
         command
@@ -27,8 +29,11 @@ pub fn make_command(command: Command) -> Command {
 pub fn execute(matches: &ArgMatches) -> Result<()> {
     let exe = env::current_exe()?;
 
-    if is_homebrew_install() {
-        println!("This installation of gsoc2-cli is managed through homebrew");
+    if let Some(prefix) = homebrew_prefix() {
+        println!(
+            "This installation of gsoc2-cli is managed through homebrew ({})",
+            prefix.display()
+        );
         println!("Please use homebrew to uninstall gsoc2-cli");
         println!();
         println!("{} brew uninstall gsoc2-cli", style("$").dim());
@@ -45,13 +50,6 @@ pub fn execute(matches: &ArgMatches) -> Result<()> {
         println!("  npm uninstall --global @gsoc2/cli");
         return Err(QuietExit(1).into());
     }
-    if cfg!(windows) {
-        println!("Cannot uninstall on Windows :(");
-        println!();
-        println!("Delete this file yourself: {}", exe.display());
-        return Err(QuietExit(1).into());
-    }
-
     // It's not currently possible to easily mock I/O with `trycmd`,
     // but verifying that `execute` is not panicking, is good enough for now.
     if env::var("GSOC2_INTEGRATION_TEST").is_ok() {
@@ -66,11 +64,14 @@ pub fn execute(matches: &ArgMatches) -> Result<()> {
         return Ok(());
     }
 
+    // See `displace_file` for why renaming beats deleting here.
     if !is_writable(&exe) {
         println!("Need to sudo to uninstall {}", exe.display());
-        runas::Command::new("rm").arg("-f").arg(exe).status()?;
+        let tmp = displace_file_elevated(&exe)?;
+        remove_displaced_file_elevated(&tmp)?;
     } else {
-        fs::remove_file(&exe)?;
+        let displaced = displace_file(&exe)?;
+        remove_displaced_file(&displaced);
     }
     println!("Uninstalled!");
 
@@ -0,0 +1,50 @@
+use std::env;
+use std::fs;
+
+use anyhow::Result;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use crate::utils::fs::{displace_file, remove_displaced_file};
+use crate::utils::update::{download_latest_gsoc2cli, latest_gsoc2cli_version};
+
+pub fn make_command(command: Command) -> Command {
+    command.about("Update the gsoc2-cli executable.").arg(
+        Arg::new("force")
+            .long("force")
+            .action(ArgAction::SetTrue)
+            .help("Update even if the current version is already up to date."),
+    )
+}
+
+pub fn execute(matches: &ArgMatches) -> Result<()> {
+    let exe = env::current_exe()?;
+    let latest = latest_gsoc2cli_version()?;
+
+    if !matches.get_flag("force") && latest.is_current() {
+        println!("Already up to date!");
+        return Ok(());
+    }
+
+    let bytes = download_latest_gsoc2cli(&latest)?;
+
+    // See `displace_file` for why renaming beats overwriting here.
+    let displaced = displace_file(&exe)?;
+    if let Err(err) = fs::write(&exe, &bytes) {
+        // Best-effort restore so a failed write doesn't leave the user
+        // without a binary at all.
+        let _ = fs::rename(&displaced, &exe);
+        return Err(err.into());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&exe)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&exe, perms)?;
+    }
+
+    remove_displaced_file(&displaced);
+    println!("Updated gsoc2-cli to version {}", latest.version());
+    Ok(())
+}
@@ -0,0 +1,241 @@
+//! Implements the config access and binding for the process.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use anyhow::{bail, Result};
+use clap::ArgMatches;
+use ini::Ini;
+use log::LevelFilter;
+
+use crate::utils::auth_token::AuthToken;
+
+static CURRENT_CONFIG: OnceLock<Arc<Config>> = OnceLock::new();
+
+const DEFAULT_BASE_URL: &str = "https://gsoc2.io/";
+
+/// The sections recognized in the `.gsoc2rc` ini file (and thus valid as
+/// the left-hand side of a `--config section.key=value` override). Kept in
+/// sync with the sections the getters below actually read.
+const KNOWN_SECTIONS: &[&str] = &["http", "log", "auth", "blackbox"];
+
+/// Default cap on the blackbox log size before it's rotated, in bytes.
+const DEFAULT_BLACKBOX_MAX_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Represents the authentication method in use.
+#[derive(Debug, Clone)]
+pub enum Auth {
+    Key(String),
+    Token(AuthToken),
+}
+
+/// Represents the `gsoc2-cli` config, loaded from `.gsoc2rc` and optionally
+/// overridden by command line flags and environment variables.
+///
+/// The ini document is the single source of truth: CLI flags and
+/// `--config` overrides both write into it, so every getter automatically
+/// reflects whichever one was applied last.
+#[derive(Debug, Clone)]
+pub struct Config {
+    filename: PathBuf,
+    ini: Ini,
+}
+
+impl Config {
+    /// Loads the config from the default `.gsoc2rc` location.
+    pub fn from_cli_config() -> Result<Config> {
+        let filename = default_config_path();
+        let ini = if filename.exists() {
+            Ini::load_from_file(&filename)?
+        } else {
+            Ini::new()
+        };
+
+        Ok(Config { filename, ini })
+    }
+
+    /// Binds this config to the current process, making it available via
+    /// [`Config::current`].
+    pub fn bind_to_process(self) {
+        CURRENT_CONFIG.set(Arc::new(self)).ok();
+    }
+
+    /// Returns the config bound to the current process. Panics if no
+    /// config has been bound yet via [`Config::bind_to_process`].
+    pub fn current() -> Arc<Config> {
+        CURRENT_CONFIG
+            .get()
+            .expect("config not bound to process")
+            .clone()
+    }
+
+    pub fn get_filename(&self) -> &Path {
+        &self.filename
+    }
+
+    pub fn get_auth(&self) -> Option<Auth> {
+        if let Some(token) = self.ini.get_from(Some("auth"), "token") {
+            return Some(Auth::Token(token.parse().ok()?));
+        }
+        self.ini
+            .get_from(Some("auth"), "key")
+            .map(|key| Auth::Key(key.to_owned()))
+    }
+
+    pub fn set_auth(&mut self, auth: Auth) -> Result<()> {
+        match auth {
+            Auth::Key(key) => self.ini.with_section(Some("auth")).set("key", key),
+            Auth::Token(token) => self
+                .ini
+                .with_section(Some("auth"))
+                .set("token", token.as_str()),
+        };
+        Ok(())
+    }
+
+    pub fn get_base_url(&self) -> &str {
+        self.ini
+            .get_from(Some("http"), "url")
+            .unwrap_or(DEFAULT_BASE_URL)
+    }
+
+    pub fn set_base_url(&mut self, url: &str) -> Result<()> {
+        self.ini.with_section(Some("http")).set("url", url);
+        Ok(())
+    }
+
+    pub fn get_headers(&self) -> Vec<String> {
+        self.ini
+            .get_from(Some("http"), "headers")
+            .map(|v| v.split(',').map(str::to_owned).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn set_headers(&mut self, headers: Vec<String>) {
+        self.ini
+            .with_section(Some("http"))
+            .set("headers", headers.join(","));
+    }
+
+    pub fn get_log_level(&self) -> Option<LevelFilter> {
+        self.ini
+            .get_from(Some("log"), "level")
+            .and_then(|v| v.parse().ok())
+    }
+
+    pub fn set_log_level(&mut self, log_level: LevelFilter) {
+        self.ini
+            .with_section(Some("log"))
+            .set("level", log_level.to_string());
+    }
+
+    /// Applies a single `section.key=value` override on top of whatever was
+    /// loaded from the ini file. Used by the global `--config` flag.
+    pub fn set_value_from_override(&mut self, spec: &str) -> Result<()> {
+        let (path, value) = match spec.split_once('=') {
+            Some(parts) => parts,
+            None => bail!("invalid --config override `{}`: expected section.key=value", spec),
+        };
+
+        let (section, key) = match path.split_once('.') {
+            Some(parts) => parts,
+            None => bail!("invalid --config override `{}`: expected section.key=value", spec),
+        };
+
+        if !KNOWN_SECTIONS.contains(&section) {
+            bail!("invalid --config override `{}`: unknown section `{}`", spec, section);
+        }
+
+        self.ini
+            .with_section(Some(section))
+            .set(key.to_owned(), value.to_owned());
+
+        Ok(())
+    }
+
+    /// Returns whether the `--allow-failure` flag or `GSOC2_ALLOW_FAILURE`
+    /// env var is set.
+    pub fn get_allow_failure(&self, matches: &ArgMatches) -> bool {
+        matches.get_flag("allow_failure") || env::var("GSOC2_ALLOW_FAILURE").is_ok()
+    }
+
+    /// Whether the blackbox invocation audit log is enabled. Opt-in via
+    /// `blackbox.enabled=true` in the config file (or `--config`).
+    pub fn get_blackbox_enabled(&self) -> bool {
+        self.ini
+            .get_from(Some("blackbox"), "enabled")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false)
+    }
+
+    /// The path the blackbox log is written to, defaulting to
+    /// `~/.gsoc2/blackbox.log`.
+    pub fn get_blackbox_path(&self) -> PathBuf {
+        self.ini
+            .get_from(Some("blackbox"), "path")
+            .map(PathBuf::from)
+            .unwrap_or_else(crate::utils::blackbox::default_path)
+    }
+
+    /// The size, in bytes, the blackbox log may reach before it's rotated.
+    pub fn get_blackbox_max_size(&self) -> u64 {
+        self.ini
+            .get_from(Some("blackbox"), "max-size")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_BLACKBOX_MAX_SIZE)
+    }
+}
+
+fn default_config_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".gsoc2rc")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> Config {
+        Config {
+            filename: PathBuf::from(".gsoc2rc"),
+            ini: Ini::new(),
+        }
+    }
+
+    #[test]
+    fn set_value_from_override_applies_known_section() {
+        let mut config = test_config();
+        config
+            .set_value_from_override("http.url=https://example.test/")
+            .unwrap();
+        assert_eq!(config.get_base_url(), "https://example.test/");
+    }
+
+    #[test]
+    fn set_value_from_override_takes_effect_through_getters() {
+        let mut config = test_config();
+        config.set_value_from_override("log.level=debug").unwrap();
+        assert_eq!(config.get_log_level(), Some(LevelFilter::Debug));
+    }
+
+    #[test]
+    fn set_value_from_override_rejects_missing_equals() {
+        let mut config = test_config();
+        assert!(config.set_value_from_override("http.url").is_err());
+    }
+
+    #[test]
+    fn set_value_from_override_rejects_missing_dot() {
+        let mut config = test_config();
+        assert!(config.set_value_from_override("url=https://example.test/").is_err());
+    }
+
+    #[test]
+    fn set_value_from_override_rejects_unconsumed_section() {
+        let mut config = test_config();
+        assert!(config.set_value_from_override("defaults.key=value").is_err());
+        assert!(config.set_value_from_override("bogus.key=value").is_err());
+    }
+}
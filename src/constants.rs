@@ -0,0 +1,10 @@
+//! Searches, parses and provides the binary's metadata.
+
+/// The current version of the CLI.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The platform identifier.
+pub const PLATFORM: &str = env!("CARGO_CFG_TARGET_OS");
+
+/// The architecture identifier.
+pub const ARCH: &str = env!("CARGO_CFG_TARGET_ARCH");
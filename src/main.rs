@@ -0,0 +1,9 @@
+mod api;
+mod commands;
+mod config;
+mod constants;
+mod utils;
+
+fn main() {
+    commands::main();
+}
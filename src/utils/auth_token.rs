@@ -0,0 +1,28 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Error;
+
+/// A Gsoc2 auth token, as supplied via `--auth-token` or the config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthToken(String);
+
+impl AuthToken {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for AuthToken {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(AuthToken(s.to_owned()))
+    }
+}
+
+impl fmt::Display for AuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,143 @@
+//! A rotating, opt-in audit log of every `gsoc2-cli` invocation.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use log::debug;
+
+use crate::config::Config;
+use crate::constants::{ARCH, PLATFORM, VERSION};
+
+static INVOCATION: OnceLock<Mutex<Invocation>> = OnceLock::new();
+
+struct Invocation {
+    start: Instant,
+    start_utc: DateTime<Utc>,
+    argv: String,
+    subcommand: String,
+}
+
+/// Arms the blackbox log for this process. Must be called once, before the
+/// subcommand is dispatched; the recorded duration runs from this call to
+/// [`finish`].
+pub fn begin(argv: String) {
+    let invocation = Invocation {
+        start: Instant::now(),
+        start_utc: Utc::now(),
+        argv,
+        subcommand: String::new(),
+    };
+    INVOCATION.set(Mutex::new(invocation)).ok();
+}
+
+/// Records which subcommand was dispatched, once it's known.
+pub fn set_subcommand(name: &str) {
+    if let Some(invocation) = INVOCATION.get() {
+        invocation.lock().unwrap().subcommand = name.to_owned();
+    }
+}
+
+/// Appends one line to the blackbox log recording how the invocation
+/// completed. A no-op if the blackbox log isn't enabled in the config, or
+/// if [`begin`] was never called.
+pub fn finish(exit_code: i32) {
+    let Some(invocation) = INVOCATION.get() else {
+        return;
+    };
+
+    if let Err(err) = write_entry(&invocation.lock().unwrap(), exit_code) {
+        debug!("failed to write blackbox log: {}", err);
+    }
+}
+
+fn write_entry(invocation: &Invocation, exit_code: i32) -> anyhow::Result<()> {
+    let config = Config::current();
+    if !config.get_blackbox_enabled() {
+        return Ok(());
+    }
+
+    let path = config.get_blackbox_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    rotate_if_needed(&path, config.get_blackbox_max_size())?;
+
+    let line = format!(
+        "{}\t{:.3}s\t{}\t{}\t{}\t{}\t{}\t{}\n",
+        invocation.start_utc.to_rfc3339(),
+        invocation.start.elapsed().as_secs_f64(),
+        if invocation.subcommand.is_empty() {
+            "-"
+        } else {
+            &invocation.subcommand
+        },
+        invocation.argv,
+        VERSION,
+        PLATFORM,
+        ARCH,
+        exit_code,
+    );
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+fn rotate_if_needed(path: &Path, max_size: u64) -> anyhow::Result<()> {
+    let Ok(metadata) = fs::metadata(path) else {
+        return Ok(());
+    };
+    if metadata.len() <= max_size {
+        return Ok(());
+    }
+
+    let mut rotated_name = path.file_name().unwrap_or_default().to_os_string();
+    rotated_name.push(".1");
+    fs::rename(path, path.with_file_name(rotated_name))?;
+    Ok(())
+}
+
+/// Resolves the default blackbox log path, `~/.gsoc2/blackbox.log`.
+pub fn default_path() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".gsoc2")
+        .join("blackbox.log")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate_if_needed_leaves_small_file_alone() {
+        let dir = std::env::temp_dir().join("gsoc2-cli-test-blackbox-small");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("blackbox.log");
+        fs::write(&path, b"short").unwrap();
+
+        rotate_if_needed(&path, 1024).unwrap();
+
+        assert!(path.exists());
+        assert!(!path.with_file_name("blackbox.log.1").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rotate_if_needed_renames_oversized_file() {
+        let dir = std::env::temp_dir().join("gsoc2-cli-test-blackbox-big");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("blackbox.log");
+        fs::write(&path, vec![0u8; 128]).unwrap();
+
+        rotate_if_needed(&path, 64).unwrap();
+
+        assert!(!path.exists());
+        assert!(path.with_file_name("blackbox.log.1").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
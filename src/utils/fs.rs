@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use anyhow::{bail, Result};
+use log::debug;
+
+/// Checks whether the current user can actually write to the directory
+/// containing `path` (which is what replacing or removing it requires).
+///
+/// Checking the readonly bit on `path`'s own metadata isn't enough: on
+/// Unix it only reflects the owner-write permission bit, so a root-owned
+/// `0755` binary run by a non-root user is reported writable even though
+/// it can't be renamed or deleted by that user. We probe by actually
+/// creating a throwaway file in the directory instead.
+pub fn is_writable<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let probe = dir.join(format!(".gsoc2-cli-write-test-{}", process::id()));
+    match fs::File::create(&probe) {
+        Ok(_) => {
+            let _ = fs::remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// Returns the sibling path used to temporarily park a displaced file.
+pub fn sibling_tmp_path(path: &Path) -> PathBuf {
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".old");
+    path.with_file_name(tmp_name)
+}
+
+/// Moves `path` to a sibling temporary file in the same directory and
+/// returns the new path.
+///
+/// Both Windows and Unix allow renaming a file that is currently mapped
+/// into a running process, even though neither allows deleting or
+/// overwriting it in place. This lets us uninstall or replace the
+/// executable that is itself currently running.
+pub fn displace_file(path: &Path) -> Result<PathBuf> {
+    let tmp = sibling_tmp_path(path);
+    fs::rename(path, &tmp)?;
+    Ok(tmp)
+}
+
+/// Moves `path` to a sibling temporary file via an elevated helper
+/// process, for use when the caller can't write to `path`'s directory
+/// directly. See [`displace_file`].
+pub fn displace_file_elevated(path: &Path) -> Result<PathBuf> {
+    let tmp = sibling_tmp_path(path);
+    let status = if cfg!(windows) {
+        runas::Command::new("cmd")
+            .arg("/c")
+            .arg("move")
+            .arg("/y")
+            .arg(path)
+            .arg(&tmp)
+            .status()?
+    } else {
+        runas::Command::new("mv").arg(path).arg(&tmp).status()?
+    };
+    if !status.success() {
+        bail!("failed to move {} to {}", path.display(), tmp.display());
+    }
+    Ok(tmp)
+}
+
+/// Best-effort cleanup of a file previously returned by [`displace_file`].
+///
+/// The rename already freed up the original path, which is all that's
+/// actually required, so a failure here is only logged rather than
+/// propagated. On Windows this matters in particular: scheduling deletion
+/// of a file still mapped into a running process needs `SeRestorePrivilege`
+/// (i.e. an elevated process), which most `gsoc2-cli` installs don't have.
+#[cfg(windows)]
+pub fn remove_displaced_file(path: &Path) {
+    use std::os::windows::ffi::OsStrExt;
+
+    use windows_sys::Win32::Storage::FileSystem::{MoveFileExW, MOVEFILE_DELAY_UNTIL_REBOOT};
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let ok = unsafe { MoveFileExW(wide.as_ptr(), std::ptr::null(), MOVEFILE_DELAY_UNTIL_REBOOT) };
+    if ok == 0 {
+        debug!(
+            "failed to schedule {} for deletion on next reboot, leaving it in place",
+            path.display()
+        );
+    }
+}
+
+/// Best-effort cleanup of a file previously returned by [`displace_file`].
+#[cfg(not(windows))]
+pub fn remove_displaced_file(path: &Path) {
+    if let Err(err) = fs::remove_file(path) {
+        debug!("failed to remove {}: {}", path.display(), err);
+    }
+}
+
+/// Removes a file previously returned by [`displace_file_elevated`].
+///
+/// On Windows this goes through [`remove_displaced_file`]'s reboot-delayed
+/// deletion rather than a direct `del`: the displaced file may still be
+/// memory-mapped by the running process, and that can't be hard-deleted
+/// even by an elevated one. It runs in the current process; no elevated
+/// helper is started, so scheduling the deletion only succeeds when the
+/// current process is already elevated. Otherwise the failure is logged
+/// and the file is left in place.
+#[cfg(windows)]
+pub fn remove_displaced_file_elevated(path: &Path) -> Result<()> {
+    remove_displaced_file(path);
+    Ok(())
+}
+
+/// Removes a file previously returned by [`displace_file_elevated`], via an
+/// elevated helper process.
+#[cfg(not(windows))]
+pub fn remove_displaced_file_elevated(path: &Path) -> Result<()> {
+    let status = runas::Command::new("rm").arg("-f").arg(path).status()?;
+    if !status.success() {
+        bail!("failed to remove {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sibling_tmp_path_adds_old_suffix() {
+        let path = Path::new("/usr/local/bin/gsoc2-cli");
+        assert_eq!(
+            sibling_tmp_path(path),
+            Path::new("/usr/local/bin/gsoc2-cli.old")
+        );
+    }
+
+    #[test]
+    fn displace_file_renames_and_returns_tmp_path() {
+        let dir = std::env::temp_dir().join(format!("gsoc2-cli-test-{:?}", std::thread::current().id()));
+        fs::create_dir_all(&dir).unwrap();
+        let exe = dir.join("gsoc2-cli");
+        fs::write(&exe, b"binary").unwrap();
+
+        let tmp = displace_file(&exe).unwrap();
+
+        assert!(!exe.exists());
+        assert_eq!(fs::read(&tmp).unwrap(), b"binary");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use log::{Level, Log, Metadata, Record};
+
+static QUIET_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables quiet mode for the logger.
+pub fn set_quiet_mode(quiet: bool) {
+    QUIET_MODE.store(quiet, Ordering::Relaxed);
+}
+
+pub fn is_quiet_mode() -> bool {
+    QUIET_MODE.load(Ordering::Relaxed)
+}
+
+/// The logger used by the `gsoc2-cli` binary.
+pub struct Logger;
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record) {
+        if is_quiet_mode() || !self.enabled(record.metadata()) {
+            return;
+        }
+        eprintln!("[{}] {}", record.level(), record.args());
+    }
+
+    fn flush(&self) {}
+}
@@ -0,0 +1,8 @@
+pub mod auth_token;
+pub mod blackbox;
+pub mod fs;
+pub mod logging;
+pub mod shell_completions;
+pub mod system;
+pub mod ui;
+pub mod update;
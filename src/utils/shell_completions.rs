@@ -0,0 +1,169 @@
+//! Resolves where a shell expects its completion files to live, so
+//! `completions --install` can write them there directly.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use anyhow::{anyhow, bail, Result};
+use clap_complete::Shell;
+
+/// Detects the shell the user is currently running, preferring the parent
+/// process name over `$SHELL` (which only reflects the login shell and
+/// goes stale once the user launches a different one interactively).
+pub fn detect_shell() -> Option<Shell> {
+    if let Some(shell) = parent_process_name().and_then(|name| shell_from_name(&name)) {
+        return Some(shell);
+    }
+
+    if let Ok(shell) = env::var("SHELL") {
+        if let Some(shell) = Path::new(&shell)
+            .file_name()
+            .and_then(|f| f.to_str())
+            .and_then(shell_from_name)
+        {
+            return Some(shell);
+        }
+    }
+
+    if env::var("PSModulePath").is_ok() {
+        return Some(Shell::PowerShell);
+    }
+
+    None
+}
+
+fn shell_from_name(name: &str) -> Option<Shell> {
+    match name {
+        "bash" => Some(Shell::Bash),
+        "zsh" => Some(Shell::Zsh),
+        "fish" => Some(Shell::Fish),
+        "elvish" => Some(Shell::Elvish),
+        "pwsh" | "powershell" => Some(Shell::PowerShell),
+        _ => None,
+    }
+}
+
+/// Looks up the executable name of the parent process.
+///
+/// We can't rely on `$PPID`: it's a bash/zsh *shell* variable, not something
+/// exported into the child process environment, so it's empty in virtually
+/// every real terminal session. Instead we ask `ps` for our own parent pid
+/// directly, which works regardless of what shell (if any) launched us.
+fn parent_process_name() -> Option<String> {
+    let ppid = parent_pid()?;
+    process_name(&ppid)
+}
+
+fn parent_pid() -> Option<String> {
+    let pid = process::id().to_string();
+    let output = Command::new("ps").args(["-p", &pid, "-o", "ppid="]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let ppid = String::from_utf8(output.stdout).ok()?;
+    let ppid = ppid.trim();
+    if ppid.is_empty() {
+        None
+    } else {
+        Some(ppid.to_owned())
+    }
+}
+
+fn process_name(pid: &str) -> Option<String> {
+    let output = Command::new("ps").args(["-p", pid, "-o", "comm="]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let name = String::from_utf8(output.stdout).ok()?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    Some(name.rsplit('/').next().unwrap_or(name).to_owned())
+}
+
+/// Resolves the conventional per-user path a shell's completion file for
+/// `bin_name` is installed to.
+pub fn completion_path(shell: Shell, bin_name: &str) -> Result<PathBuf> {
+    let home = dirs::home_dir().ok_or_else(|| anyhow!("could not determine home directory"))?;
+
+    Ok(match shell {
+        Shell::Bash => home
+            .join(".local/share/bash-completion/completions")
+            .join(bin_name),
+        Shell::Zsh => home.join(".zsh/completions").join(format!("_{bin_name}")),
+        Shell::Fish => home
+            .join(".config/fish/completions")
+            .join(format!("{bin_name}.fish")),
+        Shell::PowerShell => home
+            .join(".config/powershell")
+            .join(format!("{bin_name}-completion.ps1")),
+        other => bail!("no standard completion directory for {other}"),
+    })
+}
+
+/// The one-line snippet the user may still need to add to their rc file
+/// for the installed completion to take effect. Empty if the shell picks
+/// it up automatically once the file exists.
+pub fn rc_snippet(shell: Shell, path: &Path) -> Option<String> {
+    match shell {
+        Shell::Bash => Some(format!("source {}", path.display())),
+        Shell::Zsh => Some("fpath+=(~/.zsh/completions) && autoload -U compinit && compinit".into()),
+        Shell::PowerShell => Some(format!(". {}", path.display())),
+        Shell::Fish => None,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shell_from_name_recognizes_known_shells() {
+        assert_eq!(shell_from_name("zsh"), Some(Shell::Zsh));
+        assert_eq!(shell_from_name("bash"), Some(Shell::Bash));
+        assert_eq!(shell_from_name("pwsh"), Some(Shell::PowerShell));
+        assert_eq!(shell_from_name("tcsh"), None);
+    }
+
+    #[test]
+    fn completion_path_uses_conventional_directories() {
+        assert!(completion_path(Shell::Zsh, "gsoc2-cli")
+            .unwrap()
+            .ends_with(".zsh/completions/_gsoc2-cli"));
+        assert!(completion_path(Shell::Fish, "gsoc2-cli")
+            .unwrap()
+            .ends_with(".config/fish/completions/gsoc2-cli.fish"));
+    }
+
+    #[test]
+    fn completion_path_rejects_shells_without_a_standard_directory() {
+        assert!(completion_path(Shell::Elvish, "gsoc2-cli").is_err());
+    }
+
+    #[test]
+    fn rc_snippet_is_none_for_fish() {
+        assert_eq!(rc_snippet(Shell::Fish, Path::new("/tmp/x.fish")), None);
+    }
+
+    #[test]
+    fn rc_snippet_sources_the_installed_file_for_bash() {
+        let snippet = rc_snippet(Shell::Bash, Path::new("/tmp/gsoc2-cli")).unwrap();
+        assert!(snippet.contains("/tmp/gsoc2-cli"));
+    }
+
+    #[test]
+    fn parent_pid_is_a_real_pid_not_our_own() {
+        let ppid = parent_pid().expect("ps should resolve our parent pid");
+        assert!(ppid.parse::<u32>().is_ok());
+        assert_ne!(ppid, process::id().to_string());
+    }
+
+    #[test]
+    fn process_name_resolves_our_own_pid() {
+        let name = process_name(&process::id().to_string());
+        assert!(name.is_some());
+    }
+}
@@ -0,0 +1,103 @@
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use anyhow::Error;
+
+/// A special error type that when returned from `main` causes the process
+/// to exit with the given code without printing anything.
+#[derive(Debug)]
+pub struct QuietExit(pub i32);
+
+impl fmt::Display for QuietExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "exit with status {}", self.0)
+    }
+}
+
+impl std::error::Error for QuietExit {}
+
+/// Initializes the backtrace support for panics.
+pub fn init_backtrace() {
+    if env::var("RUST_BACKTRACE").is_err() {
+        env::set_var("RUST_BACKTRACE", "1");
+    }
+}
+
+/// Loads environment variables from a `.env` file if present.
+pub fn load_dotenv() {
+    if let Ok(path) = env::var("GSOC2_DOTENV_PATH") {
+        let _ = dotenv::from_path(path);
+    } else {
+        let _ = dotenv::dotenv();
+    }
+}
+
+/// Prints an error to stderr.
+pub fn print_error(err: &Error) {
+    eprintln!("error: {err}");
+    for cause in err.chain().skip(1) {
+        eprintln!("  caused by: {cause}");
+    }
+}
+
+/// Resolves the Homebrew prefix `gsoc2-cli` is installed under, if any.
+///
+/// Homebrew lives at `/usr/local` on Intel macOS and `/opt/homebrew` on
+/// Apple Silicon, and can be relocated entirely via `$HOMEBREW_PREFIX`. We
+/// honor the env var first, then fall back to probing both standard
+/// prefixes, and only call it Homebrew-managed if the running executable
+/// actually resolves under `<prefix>/Cellar` or `<prefix>/bin`.
+pub fn homebrew_prefix() -> Option<PathBuf> {
+    let exe = env::current_exe().ok()?;
+
+    if let Ok(prefix) = env::var("HOMEBREW_PREFIX") {
+        let prefix = PathBuf::from(prefix);
+        if exe_under_prefix(&exe, &prefix) {
+            return Some(prefix);
+        }
+    }
+
+    ["/opt/homebrew", "/usr/local"]
+        .into_iter()
+        .map(PathBuf::from)
+        .find(|prefix| exe_under_prefix(&exe, prefix))
+}
+
+fn exe_under_prefix(exe: &Path, prefix: &Path) -> bool {
+    exe.starts_with(prefix.join("Cellar")) || exe.starts_with(prefix.join("bin"))
+}
+
+/// Returns true if gsoc2-cli was installed via Homebrew.
+pub fn is_homebrew_install() -> bool {
+    homebrew_prefix().is_some()
+}
+
+/// Returns true if gsoc2-cli was installed via npm/yarn.
+pub fn is_npm_install() -> bool {
+    env::var("GSOC2_NPM_INSTALL").is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exe_under_prefix_matches_cellar() {
+        let exe = Path::new("/opt/homebrew/Cellar/gsoc2-cli/1.0.0/bin/gsoc2-cli");
+        assert!(exe_under_prefix(exe, Path::new("/opt/homebrew")));
+    }
+
+    #[test]
+    fn exe_under_prefix_matches_bin_symlink() {
+        let exe = Path::new("/usr/local/bin/gsoc2-cli");
+        assert!(exe_under_prefix(exe, Path::new("/usr/local")));
+    }
+
+    #[test]
+    fn exe_under_prefix_rejects_unrelated_path() {
+        let exe = Path::new("/home/user/.local/bin/gsoc2-cli");
+        assert!(!exe_under_prefix(exe, Path::new("/opt/homebrew")));
+        assert!(!exe_under_prefix(exe, Path::new("/usr/local")));
+    }
+}
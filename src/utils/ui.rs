@@ -0,0 +1,13 @@
+use std::io::{self, Write};
+
+use anyhow::Result;
+
+/// Prompts the user with a yes/no question and returns `true` if they
+/// confirmed.
+pub fn prompt_to_continue(message: &str) -> Result<bool> {
+    print!("{message} [y/N] ");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
